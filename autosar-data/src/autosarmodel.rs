@@ -945,6 +945,126 @@ impl AutosarModel {
         broken_refs
     }
 
+    /// find all elements in the model whose CATEGORY sub element is equal to the given value
+    ///
+    /// The model is searched depth first, so the returned elements are in the same order as in `elements_dfs()`.
+    ///
+    /// # Parameters
+    ///
+    ///  - `category`: The category value to search for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # fn main() -> Result<(), AutosarDataError> {
+    /// # let model = AutosarModel::new();
+    /// for element in model.find_elements_by_category("XCP") {
+    ///     // [...]
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_elements_by_category(&self, category: &str) -> Vec<Element> {
+        self.elements_dfs()
+            .filter_map(|(_, element)| {
+                element
+                    .get_sub_element(ElementName::Category)
+                    .and_then(|cat| cat.character_data())
+                    .and_then(|cdata| cdata.string_value())
+                    .filter(|value| value == category)
+                    .map(|_| element)
+            })
+            .collect()
+    }
+
+    /// find all elements in the ELEMENTS of any AR-PACKAGE that are not referenced anywhere in the model
+    ///
+    /// Only external references are counted: an element is referenced if a reference located outside of it
+    /// points to the element itself or to anything below it. References from inside an element to its own
+    /// sub elements do not count.
+    /// Elements of types which are not normally referenced, like SYSTEM and ECU-INSTANCE, are never reported.
+    ///
+    /// The returned list is sorted by the Autosar paths of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # fn main() -> Result<(), AutosarDataError> {
+    /// # let model = AutosarModel::new();
+    /// for element in model.find_orphaned_elements() {
+    ///     // remove the element?
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_orphaned_elements(&self) -> Vec<Element> {
+        const STANDALONE_ELEMENTS: [ElementName; 2] = [ElementName::System, ElementName::EcuInstance];
+
+        // copy the reference data, so that the model is not locked while the paths of the origins are determined
+        let reference_origins: Vec<(String, Vec<WeakElement>)> = self
+            .0
+            .read()
+            .reference_origins
+            .iter()
+            .map(|(refpath, origins)| (refpath.clone(), origins.clone()))
+            .collect();
+
+        // collect the path of each reference target, as well as the paths of all of its (named) parents.
+        // A path only counts as referenced if at least one reference originates outside of it.
+        let mut referenced_paths = HashSet::new();
+        for (refpath, origins) in reference_origins {
+            // the path of the named parent of each referencing element; None if it could not be determined
+            let origin_paths: Vec<Option<String>> = origins
+                .iter()
+                .map(|weak| {
+                    weak.upgrade()
+                        .and_then(|origin| origin.named_parent().ok().flatten())
+                        .and_then(|parent| parent.path().ok())
+                })
+                .collect();
+            let target_paths = std::iter::once(refpath.as_str()).chain(
+                refpath
+                    .rmatch_indices('/')
+                    .filter(|(idx, _)| *idx > 0)
+                    .map(|(idx, _)| &refpath[..idx]),
+            );
+            for target_path in target_paths {
+                let is_inside_target = |origin_path: &str| {
+                    origin_path
+                        .strip_prefix(target_path)
+                        .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with('/'))
+                };
+                let has_external_origin = origin_paths
+                    .iter()
+                    .any(|origin_path| !origin_path.as_deref().is_some_and(is_inside_target));
+                if has_external_origin {
+                    referenced_paths.insert(target_path.to_owned());
+                }
+            }
+        }
+
+        let mut orphans: Vec<(String, Element)> = self
+            .identifiable_elements()
+            .filter(|(path, _)| !referenced_paths.contains(path))
+            .filter_map(|(path, weak)| weak.upgrade().map(|element| (path, element)))
+            .filter(|(_, element)| {
+                !STANDALONE_ELEMENTS.contains(&element.element_name())
+                    && element
+                        .parent()
+                        .ok()
+                        .flatten()
+                        .is_some_and(|parent| parent.element_name() == ElementName::Elements)
+            })
+            .collect();
+        orphans.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+        orphans.into_iter().map(|(_, element)| element).collect()
+    }
+
     /// create a weak reference to this data
     pub(crate) fn downgrade(&self) -> WeakAutosarModel {
         WeakAutosarModel(Arc::downgrade(&self.0))
@@ -1627,6 +1747,88 @@ mod test {
         }
     }
 
+    #[test]
+    fn find_elements_by_category() {
+        const FILEBUF: &[u8] = r#"<?xml version="1.0" encoding="utf-8"?>
+        <AUTOSAR xsi:schemaLocation="http://autosar.org/schema/r4.0 AUTOSAR_00050.xsd" xmlns="http://autosar.org/schema/r4.0" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+        <AR-PACKAGES>
+          <AR-PACKAGE><SHORT-NAME>Pkg</SHORT-NAME><CATEGORY>XCP</CATEGORY><ELEMENTS>
+            <I-SIGNAL><SHORT-NAME>Sig1</SHORT-NAME><CATEGORY>XCP</CATEGORY></I-SIGNAL>
+            <I-SIGNAL><SHORT-NAME>Sig2</SHORT-NAME><CATEGORY>OTHER</CATEGORY></I-SIGNAL>
+            <I-SIGNAL><SHORT-NAME>Sig3</SHORT-NAME></I-SIGNAL>
+          </ELEMENTS></AR-PACKAGE>
+        </AR-PACKAGES></AUTOSAR>"#.as_bytes();
+        let model = AutosarModel::new();
+        let (_, _) = model.load_buffer(FILEBUF, "test1", true).unwrap();
+
+        let xcp_elements = model.find_elements_by_category("XCP");
+        assert_eq!(xcp_elements.len(), 2);
+        assert_eq!(xcp_elements[0].path().unwrap(), "/Pkg");
+        assert_eq!(xcp_elements[1].path().unwrap(), "/Pkg/Sig1");
+
+        let other_elements = model.find_elements_by_category("OTHER");
+        assert_eq!(other_elements.len(), 1);
+        assert_eq!(other_elements[0].item_name().unwrap(), "Sig2");
+
+        assert!(model.find_elements_by_category("MISSING").is_empty());
+    }
+
+    #[test]
+    fn find_orphaned_elements() {
+        const FILEBUF: &[u8] = r#"<?xml version="1.0" encoding="utf-8"?>
+        <AUTOSAR xsi:schemaLocation="http://autosar.org/schema/r4.0 AUTOSAR_00050.xsd" xmlns="http://autosar.org/schema/r4.0" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+        <AR-PACKAGES>
+          <AR-PACKAGE><SHORT-NAME>Pkg</SHORT-NAME><ELEMENTS>
+            <SYSTEM><SHORT-NAME>System</SHORT-NAME></SYSTEM>
+            <ECU-INSTANCE><SHORT-NAME>Ecu</SHORT-NAME></ECU-INSTANCE>
+            <I-SIGNAL><SHORT-NAME>Sig</SHORT-NAME>
+              <SYSTEM-SIGNAL-REF DEST="SYSTEM-SIGNAL">/Pkg/SysSig</SYSTEM-SIGNAL-REF>
+            </I-SIGNAL>
+            <SYSTEM-SIGNAL><SHORT-NAME>SysSig</SHORT-NAME></SYSTEM-SIGNAL>
+            <ECUC-MODULE-CONFIGURATION-VALUES><SHORT-NAME>Mod</SHORT-NAME><CONTAINERS>
+              <ECUC-CONTAINER-VALUE><SHORT-NAME>Cont</SHORT-NAME></ECUC-CONTAINER-VALUE>
+            </CONTAINERS></ECUC-MODULE-CONFIGURATION-VALUES>
+            <ECUC-MODULE-CONFIGURATION-VALUES><SHORT-NAME>Mod2</SHORT-NAME><CONTAINERS>
+              <ECUC-CONTAINER-VALUE><SHORT-NAME>Cont2</SHORT-NAME><REFERENCE-VALUES>
+                <ECUC-REFERENCE-VALUE>
+                  <VALUE-REF DEST="ECUC-CONTAINER-VALUE">/Pkg/Mod/Cont</VALUE-REF>
+                </ECUC-REFERENCE-VALUE>
+              </REFERENCE-VALUES></ECUC-CONTAINER-VALUE>
+            </CONTAINERS></ECUC-MODULE-CONFIGURATION-VALUES>
+            <ECUC-MODULE-CONFIGURATION-VALUES><SHORT-NAME>Mod3</SHORT-NAME><CONTAINERS>
+              <ECUC-CONTAINER-VALUE><SHORT-NAME>A</SHORT-NAME></ECUC-CONTAINER-VALUE>
+              <ECUC-CONTAINER-VALUE><SHORT-NAME>B</SHORT-NAME><REFERENCE-VALUES>
+                <ECUC-REFERENCE-VALUE>
+                  <VALUE-REF DEST="ECUC-CONTAINER-VALUE">/Pkg/Mod3/A</VALUE-REF>
+                </ECUC-REFERENCE-VALUE>
+              </REFERENCE-VALUES></ECUC-CONTAINER-VALUE>
+            </CONTAINERS></ECUC-MODULE-CONFIGURATION-VALUES>
+          </ELEMENTS></AR-PACKAGE>
+        </AR-PACKAGES></AUTOSAR>"#.as_bytes();
+        let model = AutosarModel::new();
+        let (_, _) = model.load_buffer(FILEBUF, "test1", true).unwrap();
+
+        // SYSTEM and ECU-INSTANCE are never reported; Mod is used through a reference to its container Cont.
+        // Mod3 is an orphan, because the only reference to its container A originates inside Mod3 itself
+        let orphans = model.find_orphaned_elements();
+        let orphan_paths: Vec<String> = orphans.iter().map(|elem| elem.path().unwrap()).collect();
+        assert_eq!(orphan_paths, vec!["/Pkg/Mod2", "/Pkg/Mod3", "/Pkg/Sig"]);
+
+        // once the referencing signal is removed, the system signal becomes an orphan
+        let elements = model
+            .get_element_by_path("/Pkg")
+            .unwrap()
+            .get_sub_element(ElementName::Elements)
+            .unwrap();
+        elements.remove_sub_element(orphans[2].clone()).unwrap();
+        let orphan_paths: Vec<String> = model
+            .find_orphaned_elements()
+            .iter()
+            .map(|elem| elem.path().unwrap())
+            .collect();
+        assert_eq!(orphan_paths, vec!["/Pkg/Mod2", "/Pkg/Mod3", "/Pkg/SysSig"]);
+    }
+
     #[test]
     fn model_merge() {
         // from github issue #24; test files provided by FlTr